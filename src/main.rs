@@ -1,9 +1,10 @@
 use std::{
+    collections::HashSet,
     fmt::{self, Formatter},
-    fs::File,
+    fs::{self, File},
 };
 
-use chrono::{Datelike, Months, NaiveDate};
+use chrono::{Datelike, Months, NaiveDate, Weekday};
 use clap::Parser;
 
 use csv;
@@ -16,10 +17,44 @@ enum Frequency {
     Weekly,
     BiWeekly,
     Monthly,
+    MonthlyByWeekday { week: i32, weekday: Weekday },
+    SemiMonthly,
     Quarterly,
     Yearly,
 }
 
+/// Parses a `mon`/`tue`/.../`sun` abbreviation into a `Weekday`.
+fn parse_weekday_abbrev(s: &str) -> Option<Weekday> {
+    match s {
+        "mon" => Some(Weekday::Mon),
+        "tue" => Some(Weekday::Tue),
+        "wed" => Some(Weekday::Wed),
+        "thu" => Some(Weekday::Thu),
+        "fri" => Some(Weekday::Fri),
+        "sat" => Some(Weekday::Sat),
+        "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parses a `<nth>-<weekday>` spec such as `2nd-fri` or `last-fri` into a
+/// week index (1-based, forward; negative, counting back from month end)
+/// and a `Weekday`.
+fn parse_nth_weekday(spec: &str) -> Option<(i32, Weekday)> {
+    let (week_part, weekday_part) = spec.split_once('-')?;
+    let weekday = parse_weekday_abbrev(weekday_part)?;
+    let week = if week_part == "last" {
+        -1
+    } else {
+        let digits: String = week_part
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        digits.parse::<i32>().ok()?
+    };
+    Some((week, weekday))
+}
+
 impl Frequency {
     fn from_str(s: &str) -> Result<Self, &'static str> {
         let s = s.to_lowercase();
@@ -35,12 +70,263 @@ impl Frequency {
             "d" | "day" | "daily" => Ok(Frequency::Daily),
             "w" | "week" | "weekly" => Ok(Frequency::Weekly),
             "biweekly" => Ok(Frequency::BiWeekly),
-            "m" | "month" | "monthly" => Ok(Frequency::Monthly),
+            "m" | "month" | "monthly" => {
+                match parts.get(1).and_then(|s| s.strip_suffix(')')) {
+                    // A bare `key=value` param (every/from/until) belongs to
+                    // `Repetition::from_str`, not the nth-weekday spec.
+                    Some(param) if !param.contains('=') => {
+                        let (week, weekday) =
+                            parse_nth_weekday(param).ok_or("Invalid monthly-by-weekday spec")?;
+                        Ok(Frequency::MonthlyByWeekday { week, weekday })
+                    }
+                    _ => Ok(Frequency::Monthly),
+                }
+            }
+            "semimonthly" | "twice-a-month" => Ok(Frequency::SemiMonthly),
             "quarter" | "quarterly" => Ok(Frequency::Quarterly),
             "y" | "year" | "yearly" => Ok(Frequency::Yearly),
             _ => Err("Invalid frequency"),
         }
     }
+
+    /// The last day of the month that `first_of_month` (any date in that
+    /// month) falls in, derived by stepping to next month and back a day.
+    fn days_in_month(first_of_month: NaiveDate) -> u32 {
+        first_of_month
+            .with_day(1)
+            .expect("Invalid date")
+            .checked_add_months(Months::new(1))
+            .and_then(|d| d.pred_opt())
+            .expect("Invalid date")
+            .day()
+    }
+
+    /// Resolves the date matching `week`/`weekday` within the month that
+    /// `month_start` (the 1st of that month) falls in. `week` counts forward
+    /// from 1, or backward from -1 (`-1` = last).
+    fn resolve_nth_weekday(month_start: NaiveDate, week: i32, weekday: Weekday) -> Option<NaiveDate> {
+        let days_in_month = Self::days_in_month(month_start);
+        for day in 1..=days_in_month {
+            let date = month_start.with_day(day)?;
+            if date.weekday() != weekday {
+                continue;
+            }
+            let forward_week = (day - 1) / 7 + 1;
+            let backward_week = (days_in_month - day) / 7 + 1;
+            if forward_week as i32 == week || -(backward_week as i32) == week {
+                return Some(date);
+            }
+        }
+        None
+    }
+
+    /// Step `date` forward by `interval` periods of this frequency's recurrence.
+    /// `OneTime` has no period and never recurs.
+    fn step_by(&self, date: NaiveDate, interval: u32) -> Option<NaiveDate> {
+        match self {
+            Frequency::OneTime(_) => None,
+            Frequency::Daily => date.checked_add_signed(chrono::Duration::days(interval as i64)),
+            Frequency::Weekly => {
+                date.checked_add_signed(chrono::Duration::days(7 * interval as i64))
+            }
+            Frequency::BiWeekly => {
+                date.checked_add_signed(chrono::Duration::days(14 * interval as i64))
+            }
+            Frequency::Monthly => date.checked_add_months(Months::new(interval)),
+            Frequency::MonthlyByWeekday { .. } => date.checked_add_months(Months::new(interval)),
+            Frequency::SemiMonthly => date.checked_add_months(Months::new(interval)),
+            Frequency::Quarterly => interval
+                .checked_mul(3)
+                .and_then(|months| date.checked_add_months(Months::new(months))),
+            Frequency::Yearly => interval
+                .checked_mul(12)
+                .and_then(|months| date.checked_add_months(Months::new(months))),
+        }
+    }
+
+    /// The number of whole calendar months between two first-of-month dates.
+    fn months_since(anchor_month: NaiveDate, month_start: NaiveDate) -> i32 {
+        (month_start.year() - anchor_month.year()) * 12 + month_start.month() as i32
+            - anchor_month.month() as i32
+    }
+
+    /// Enumerate the dates this frequency actually lands on within
+    /// `[month_start, month_end]`, starting the recurrence at `anchor` and
+    /// advancing `interval` periods at a time.
+    fn occurrences_in_month(
+        &self,
+        anchor: NaiveDate,
+        month_start: NaiveDate,
+        month_end: NaiveDate,
+        interval: u32,
+    ) -> Vec<NaiveDate> {
+        if let Frequency::MonthlyByWeekday { week, weekday } = self {
+            let anchor_month = anchor.with_day(1).expect("Invalid date");
+            if month_start < anchor_month
+                || Self::months_since(anchor_month, month_start) % interval as i32 != 0
+            {
+                return vec![];
+            }
+            return Self::resolve_nth_weekday(month_start, *week, *weekday)
+                .filter(|date| *date >= month_start && *date <= month_end)
+                .into_iter()
+                .collect();
+        }
+
+        if let Frequency::SemiMonthly = self {
+            let anchor_month = anchor.with_day(1).expect("Invalid date");
+            if month_start < anchor_month
+                || Self::months_since(anchor_month, month_start) % interval as i32 != 0
+            {
+                return vec![];
+            }
+            return [1, 15]
+                .into_iter()
+                .filter_map(|day| month_start.with_day(day))
+                .filter(|date| *date >= month_start && *date <= month_end)
+                .collect();
+        }
+
+        let mut dates = vec![];
+        let mut cursor = anchor;
+        while cursor < month_start {
+            cursor = match self.step_by(cursor, interval) {
+                Some(next) => next,
+                None => return dates,
+            };
+        }
+        while cursor <= month_end {
+            dates.push(cursor);
+            cursor = match self.step_by(cursor, interval) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        dates
+    }
+}
+
+#[cfg(test)]
+mod occurrences_in_month {
+    use super::Frequency;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn daily_counts_every_day_in_the_month() {
+        let month_start = NaiveDate::from_ymd_opt(2020, 2, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let dates = Frequency::Daily.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates.len(), 29);
+    }
+
+    #[test]
+    fn biweekly_can_land_three_times_in_a_month() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let dates = Frequency::BiWeekly.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates.len(), 3);
+    }
+
+    #[test]
+    fn monthly_anchor_before_window_fast_forwards_to_first_occurrence() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let dates = Frequency::Monthly.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates, vec![NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()]);
+    }
+
+    #[test]
+    fn anchor_after_window_has_no_occurrences() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let dates = Frequency::Weekly.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert!(dates.is_empty());
+    }
+
+    #[test]
+    fn one_time_never_recurs() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let dates =
+            Frequency::OneTime(None).occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates, vec![anchor]);
+    }
+
+    #[test]
+    fn interval_skips_periods() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let dates = Frequency::Weekly.occurrences_in_month(anchor, month_start, month_end, 2);
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 25).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_by_weekday_resolves_2nd_friday() {
+        use chrono::Weekday;
+
+        let month_start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+        let frequency = Frequency::MonthlyByWeekday {
+            week: 2,
+            weekday: Weekday::Fri,
+        };
+        let dates = frequency.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates, vec![NaiveDate::from_ymd_opt(2024, 7, 12).unwrap()]);
+    }
+
+    #[test]
+    fn monthly_by_weekday_resolves_last_friday() {
+        use chrono::Weekday;
+
+        let month_start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 26).unwrap();
+        let frequency = Frequency::MonthlyByWeekday {
+            week: -1,
+            weekday: Weekday::Fri,
+        };
+        let dates = frequency.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(dates, vec![NaiveDate::from_ymd_opt(2024, 7, 26).unwrap()]);
+    }
+
+    #[test]
+    fn semi_monthly_lands_on_the_1st_and_15th() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let dates =
+            Frequency::SemiMonthly.occurrences_in_month(anchor, month_start, month_end, 1);
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 7, 15).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn huge_interval_does_not_overflow_stepping() {
+        let month_start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let month_end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let dates =
+            Frequency::Yearly.occurrences_in_month(anchor, month_start, month_end, 2_000_000_000);
+        assert_eq!(dates, vec![anchor]);
+    }
 }
 
 impl fmt::Debug for Frequency {
@@ -57,6 +343,10 @@ impl fmt::Debug for Frequency {
             Frequency::Weekly => write!(f, "Weekly"),
             Frequency::BiWeekly => write!(f, "BiWeekly"),
             Frequency::Monthly => write!(f, "Monthly"),
+            Frequency::MonthlyByWeekday { week, weekday } => {
+                write!(f, "MonthlyByWeekday({week}, {weekday:?})")
+            }
+            Frequency::SemiMonthly => write!(f, "SemiMonthly"),
             Frequency::Quarterly => write!(f, "Quarterly"),
             Frequency::Yearly => write!(f, "Yearly"),
         }
@@ -73,6 +363,249 @@ impl<'de> Deserialize<'de> for Frequency {
     }
 }
 
+/// A bounded, RRULE-style recurrence: a base `Frequency` stepped every
+/// `interval` periods, optionally clamped to a `[from, until]` window.
+#[derive(Debug)]
+struct Repetition {
+    frequency: Frequency,
+    interval: u32,
+    from: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+}
+
+/// Maps a (pluralized) unit word to its base `Frequency`, e.g. `days` -> `Daily`.
+fn frequency_from_unit(unit: &str) -> Option<Frequency> {
+    match unit.strip_suffix('s').unwrap_or(unit) {
+        "day" => Some(Frequency::Daily),
+        "week" => Some(Frequency::Weekly),
+        "biweek" | "fortnight" => Some(Frequency::BiWeekly),
+        "month" => Some(Frequency::Monthly),
+        "quarter" => Some(Frequency::Quarterly),
+        "year" => Some(Frequency::Yearly),
+        _ => None,
+    }
+}
+
+/// Parses an interval, rejecting anything less than 1 — an interval of 0
+/// would make `Frequency::step_by` return the same date forever. Shared by
+/// both the `every=N` key-value syntax and the natural-language `every N
+/// <unit>` phrases so the two paths can't drift out of sync.
+fn parse_interval(s: &str) -> Result<u32, &'static str> {
+    let interval: u32 = s.trim().parse().map_err(|_| "Invalid interval")?;
+    if interval < 1 {
+        return Err("Invalid interval");
+    }
+    Ok(interval)
+}
+
+/// Tokenizes human phrases like `every 2 weeks`, `every other month`,
+/// `twice a month`, and `every 3 days` into a `(Frequency, interval)` pair.
+fn parse_natural_language_repetition(s: &str) -> Result<Option<(Frequency, u32)>, &'static str> {
+    if s == "twice a month" {
+        return Ok(Some((Frequency::SemiMonthly, 1)));
+    }
+
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    if tokens.first() != Some(&"every") {
+        return Ok(None);
+    }
+    let rest = &tokens[1..];
+    Ok(match rest {
+        ["other", unit] => frequency_from_unit(unit).map(|f| (f, 2)),
+        [n, unit] => match frequency_from_unit(unit) {
+            Some(f) => Some((f, parse_interval(n)?)),
+            None => None,
+        },
+        [unit] => frequency_from_unit(unit).map(|f| (f, 1)),
+        _ => None,
+    })
+}
+
+impl Repetition {
+    /// Parses strings like `biweekly(every=2; from=2024-01-01; until=2024-12-31)`,
+    /// or a natural-language phrase like `every 2 weeks` (see
+    /// `parse_natural_language_repetition`). The base frequency keyword and
+    /// `OneTime`'s own date or `MonthlyByWeekday`'s `<nth>-<weekday>` spec are
+    /// parsed by `Frequency::from_str`; `every`/`from`/`until` are additional
+    /// `key=value` parameters separated by `;`. `OneTime` and
+    /// `MonthlyByWeekday` take no such parameters: their interval is 1 and
+    /// they have no `from`/`until` window.
+    fn from_str(s: &str) -> Result<Self, &'static str> {
+        let lower = s.to_lowercase();
+
+        if let Some((frequency, interval)) = parse_natural_language_repetition(&lower)? {
+            return Ok(Repetition {
+                frequency,
+                interval,
+                from: None,
+                until: None,
+            });
+        }
+
+        let frequency = Frequency::from_str(&lower)?;
+
+        if matches!(
+            frequency,
+            Frequency::OneTime(_) | Frequency::MonthlyByWeekday { .. }
+        ) {
+            return Ok(Repetition {
+                frequency,
+                interval: 1,
+                from: None,
+                until: None,
+            });
+        }
+
+        let mut interval = 1u32;
+        let mut from = None;
+        let mut until = None;
+
+        if let Some(params) = lower
+            .split_once('(')
+            .and_then(|(_, rest)| rest.strip_suffix(')'))
+        {
+            for param in params.split(';') {
+                let param = param.trim();
+                if param.is_empty() {
+                    continue;
+                }
+                let (key, value) = param.split_once('=').ok_or("Invalid repetition parameter")?;
+                match key.trim() {
+                    "every" => {
+                        interval = parse_interval(value)?;
+                    }
+                    "from" => {
+                        from = Some(
+                            NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+                                .map_err(|_| "Invalid from date")?,
+                        );
+                    }
+                    "until" => {
+                        until = Some(
+                            NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+                                .map_err(|_| "Invalid until date")?,
+                        );
+                    }
+                    _ => return Err("Unknown repetition parameter"),
+                }
+            }
+        }
+
+        Ok(Repetition {
+            frequency,
+            interval,
+            from,
+            until,
+        })
+    }
+}
+
+#[cfg(test)]
+mod repetition_from_str {
+    use super::{Frequency, Repetition};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn bare_keyword_defaults_to_interval_one_and_no_window() {
+        let repetition = Repetition::from_str("monthly").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::Monthly));
+        assert_eq!(repetition.interval, 1);
+        assert_eq!(repetition.from, None);
+        assert_eq!(repetition.until, None);
+    }
+
+    #[test]
+    fn parses_interval_and_window() {
+        let repetition =
+            Repetition::from_str("biweekly(every=2; from=2024-01-01; until=2024-12-31)").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::BiWeekly));
+        assert_eq!(repetition.interval, 2);
+        assert_eq!(repetition.from, NaiveDate::from_ymd_opt(2024, 1, 1));
+        assert_eq!(repetition.until, NaiveDate::from_ymd_opt(2024, 12, 31));
+    }
+
+    #[test]
+    fn parses_monthly_by_weekday() {
+        use chrono::Weekday;
+
+        let repetition = Repetition::from_str("monthly(2nd-fri)").unwrap();
+        assert!(matches!(
+            repetition.frequency,
+            Frequency::MonthlyByWeekday {
+                week: 2,
+                weekday: Weekday::Fri,
+            }
+        ));
+        assert_eq!(repetition.interval, 1);
+
+        let repetition = Repetition::from_str("monthly(last-fri)").unwrap();
+        assert!(matches!(
+            repetition.frequency,
+            Frequency::MonthlyByWeekday {
+                week: -1,
+                weekday: Weekday::Fri,
+            }
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_monthly_by_weekday_spec() {
+        assert!(Repetition::from_str("monthly(xx-fri)").is_err());
+        assert!(Repetition::from_str("monthly(2nd_fri)").is_err());
+    }
+
+    #[test]
+    fn parses_every_n_units() {
+        let repetition = Repetition::from_str("every 2 weeks").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::Weekly));
+        assert_eq!(repetition.interval, 2);
+
+        let repetition = Repetition::from_str("every 3 days").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::Daily));
+        assert_eq!(repetition.interval, 3);
+    }
+
+    #[test]
+    fn parses_every_other_unit() {
+        let repetition = Repetition::from_str("every other month").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::Monthly));
+        assert_eq!(repetition.interval, 2);
+    }
+
+    #[test]
+    fn parses_twice_a_month_as_semi_monthly() {
+        let repetition = Repetition::from_str("twice a month").unwrap();
+        assert!(matches!(repetition.frequency, Frequency::SemiMonthly));
+        assert_eq!(repetition.interval, 1);
+    }
+
+    #[test]
+    fn rejects_unknown_parameter() {
+        assert!(Repetition::from_str("monthly(bogus=1)").is_err());
+    }
+
+    #[test]
+    fn rejects_zero_interval() {
+        assert!(Repetition::from_str("monthly(every=0)").is_err());
+    }
+
+    #[test]
+    fn rejects_zero_interval_in_natural_language() {
+        assert!(Repetition::from_str("every 0 days").is_err());
+        assert!(Repetition::from_str("every 0 weeks").is_err());
+    }
+}
+
+impl<'de> Deserialize<'de> for Repetition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Repetition::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug)]
 enum Type {
     Bill,
@@ -105,29 +638,67 @@ impl<'de> Deserialize<'de> for Type {
     }
 }
 
+fn deserialize_naive_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(serde::de::Error::custom)
+}
+
+/// Parses a `|`-separated list of `YYYY-MM-DD` dates (e.g.
+/// `2024-03-01|2024-07-01`) into the set of occurrences to drop from a
+/// recurring event. An empty column means no exceptions.
+fn deserialize_removed_occurrences<'de, D>(deserializer: D) -> Result<HashSet<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.trim().is_empty() {
+        return Ok(HashSet::new());
+    }
+    s.split('|')
+        .map(|date| {
+            NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").map_err(serde::de::Error::custom)
+        })
+        .collect()
+}
+
 #[derive(Debug, Deserialize)]
 struct CashEvent {
     name: String,
     usd: f64,
-    frequency: Frequency,
+    repetition: Repetition,
     type_: Type,
     is_taxable: bool,
+    #[serde(deserialize_with = "deserialize_naive_date")]
+    anchor_date: NaiveDate,
+    #[serde(
+        rename = "skip",
+        default,
+        deserialize_with = "deserialize_removed_occurrences"
+    )]
+    removed_occurrences: HashSet<NaiveDate>,
 }
 
 impl CashEvent {
     fn _new(
         name: String,
         usd: f64,
-        frequency: Frequency,
+        repetition: Repetition,
         type_: Type,
         is_taxable: Option<bool>,
+        anchor_date: NaiveDate,
+        removed_occurrences: HashSet<NaiveDate>,
     ) -> CashEvent {
         CashEvent {
             name,
             usd,
-            frequency,
+            repetition,
             type_,
             is_taxable: is_taxable.unwrap_or(false),
+            anchor_date,
+            removed_occurrences,
         }
     }
 
@@ -138,7 +709,7 @@ impl CashEvent {
             self.usd
         };
 
-        let amount = match self.frequency {
+        let amount = match self.repetition.frequency {
             Frequency::OneTime(one_time_date) => {
                 if let Some(one_time_date) = one_time_date {
                     if date.month() == one_time_date.month() && date.year() == one_time_date.year()
@@ -148,12 +719,35 @@ impl CashEvent {
                 }
                 return 0.0;
             }
-            Frequency::Daily => amount * 30.0,
-            Frequency::Weekly => amount * 4.5,
-            Frequency::BiWeekly => amount * 2.25,
-            Frequency::Monthly => amount,
-            Frequency::Quarterly => amount / 3.0,
-            Frequency::Yearly => amount / 12.0,
+            _ => {
+                let month_start = date.with_day(1).expect("Invalid date");
+                let month_end = month_start
+                    .checked_add_months(Months::new(1))
+                    .and_then(|d| d.pred_opt())
+                    .expect("Invalid date");
+
+                if self.repetition.from.is_some_and(|from| month_end < from)
+                    || self.repetition.until.is_some_and(|until| month_start > until)
+                {
+                    return 0.0;
+                }
+
+                let occurrences = self.repetition.frequency.occurrences_in_month(
+                    self.anchor_date,
+                    month_start,
+                    month_end,
+                    self.repetition.interval,
+                );
+                let occurrence_count = occurrences
+                    .into_iter()
+                    .filter(|occurrence| {
+                        self.repetition.from.is_none_or(|from| *occurrence >= from)
+                            && self.repetition.until.is_none_or(|until| *occurrence <= until)
+                            && !self.removed_occurrences.contains(occurrence)
+                    })
+                    .count();
+                amount * occurrence_count as f64
+            }
         };
 
         match self.type_ {
@@ -170,6 +764,82 @@ fn get_monthly_amount(cash_events: &Vec<CashEvent>, date: &NaiveDate, tax_rate:
         .sum()
 }
 
+fn deserialize_optional_naive_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) => NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// An alternative to the CSV input: a TOML file declaring a
+/// starting balance and the cash events to project forward from it.
+#[derive(Debug, Deserialize)]
+struct Scenario {
+    start_balance: f64,
+    #[serde(default, deserialize_with = "deserialize_optional_naive_date")]
+    start_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_naive_date")]
+    end_date: Option<NaiveDate>,
+    events: Vec<CashEvent>,
+}
+
+#[cfg(test)]
+mod cash_event_get_monthly_amount {
+    use super::{CashEvent, Frequency, Repetition, Type};
+    use chrono::NaiveDate;
+    use std::collections::HashSet;
+
+    fn repetition(frequency: Frequency) -> Repetition {
+        Repetition {
+            frequency,
+            interval: 1,
+            from: None,
+            until: None,
+        }
+    }
+
+    #[test]
+    fn removed_occurrence_drops_just_that_month() {
+        let anchor = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let skipped = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let event = CashEvent::_new(
+            "Rent".to_string(),
+            1000.0,
+            repetition(Frequency::Monthly),
+            Type::Bill,
+            Some(false),
+            anchor,
+            HashSet::from([skipped]),
+        );
+
+        let skipped_month_amount = event.get_monthly_amount(&skipped, 0.0);
+        assert_eq!(skipped_month_amount, 0.0);
+
+        let normal_month = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let normal_month_amount = event.get_monthly_amount(&normal_month, 0.0);
+        assert_eq!(normal_month_amount, -1000.0);
+    }
+}
+
+#[cfg(test)]
+mod cash_event_deserialize {
+    use super::CashEvent;
+
+    #[test]
+    fn skip_column_is_optional() {
+        let csv = "name,usd,repetition,type_,is_taxable,anchor_date\n\
+                    Rent,1000,monthly,bill,false,2024-01-01\n";
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let record: CashEvent = reader.deserialize().next().unwrap().unwrap();
+        assert!(record.removed_occurrences.is_empty());
+    }
+}
+
 fn get_first_day_of_months_between(start_date: &NaiveDate, end_date: &NaiveDate) -> Vec<NaiveDate> {
     if start_date > end_date {
         return vec![];
@@ -256,30 +926,63 @@ struct Args {
 
     #[arg(short, long, default_value = "0.169")]
     tax_rate: f64,
+
+    /// Input format: "csv" (default) or "toml". Inferred from the file
+    /// extension when not given.
+    #[arg(short, long)]
+    format: Option<String>,
 }
 
 fn main() {
     let args: Args = Args::parse();
 
-    let start_date = chrono::Local::now().naive_local().date();
-    let end_date = start_date
-        .checked_add_months(Months::new(args.months))
-        .expect("Invalid date");
-
-    let dates = get_first_day_of_months_between(&start_date, &end_date);
-
-    let file = File::open(args.cash_events_file_path).expect("Unable to open file");
-    let mut reader = csv::Reader::from_reader(file);
-    let mut events: Vec<CashEvent> = vec![];
-    for result in reader.deserialize() {
-        let record: CashEvent = result.expect("Unable to parse record");
+    let is_toml = match args.format.as_deref() {
+        Some("toml") => true,
+        Some("csv") => false,
+        Some(other) => panic!("Unknown format {other:?}, expected \"csv\" or \"toml\""),
+        None => args.cash_events_file_path.ends_with(".toml"),
+    };
+
+    let (events, start_balance, scenario_start_date, scenario_end_date) = if is_toml {
+        let contents =
+            fs::read_to_string(&args.cash_events_file_path).expect("Unable to read file");
+        let scenario: Scenario = toml::from_str(&contents).expect("Unable to parse scenario file");
         if args.verbose {
-            println!("{:?}", record);
+            for event in &scenario.events {
+                println!("{:?}", event);
+            }
         }
-        events.push(record);
-    }
+        (
+            scenario.events,
+            scenario.start_balance,
+            scenario.start_date,
+            scenario.end_date,
+        )
+    } else {
+        let file = File::open(&args.cash_events_file_path).expect("Unable to open file");
+        let mut reader = csv::Reader::from_reader(file);
+        let mut events: Vec<CashEvent> = vec![];
+        for result in reader.deserialize() {
+            let record: CashEvent = result.expect("Unable to parse record");
+            if args.verbose {
+                println!("{:?}", record);
+            }
+            events.push(record);
+        }
+        (events, 0.0, None, None)
+    };
+
+    let start_date =
+        scenario_start_date.unwrap_or_else(|| chrono::Local::now().naive_local().date());
+    let end_date = scenario_end_date.unwrap_or_else(|| {
+        start_date
+            .checked_add_months(Months::new(args.months))
+            .expect("Invalid date")
+    });
+
+    let dates = get_first_day_of_months_between(&start_date, &end_date);
 
-    let mut sum = 0.0;
+    let mut sum = start_balance;
     for date in dates {
         let monthly_amount = get_monthly_amount(&events, &date, args.tax_rate);
         sum += monthly_amount;